@@ -9,7 +9,7 @@ use crate::{
     solvers::SolutionWithDual,
     variable::{UnsolvedProblem, VariableDefinition},
 };
-use crate::{Constraint, IntoAffineExpression, Variable};
+use crate::{Constraint, Expression, IntoAffineExpression, Variable};
 
 /// The [highs](https://docs.rs/highs) solver,
 /// to be used with [UnsolvedProblem::using].
@@ -20,7 +20,16 @@ pub fn highs(to_solve: UnsolvedProblem) -> HighsProblem {
         ObjectiveDirection::Minimisation => highs::Sense::Minimise,
     };
     let mut columns = Vec::with_capacity(to_solve.variables.len());
-    for (var, &VariableDefinition { min, max, .. }) in to_solve.variables.iter_variables_with_def()
+    let mut column_defs = Vec::with_capacity(to_solve.variables.len());
+    for (
+        var,
+        &VariableDefinition {
+            min,
+            max,
+            is_integer,
+            ..
+        },
+    ) in to_solve.variables.iter_variables_with_def()
     {
         let &col_factor = to_solve
             .objective
@@ -28,17 +37,54 @@ pub fn highs(to_solve: UnsolvedProblem) -> HighsProblem {
             .coefficients
             .get(&var)
             .unwrap_or(&0.);
-        let col = highs_problem.add_column(col_factor, min..max);
+        let col = if is_integer {
+            highs_problem.add_integer_column(col_factor, min..max)
+        } else {
+            highs_problem.add_column(col_factor, min..max)
+        };
         columns.push(col);
+        column_defs.push(ColumnDef {
+            cost: col_factor,
+            min,
+            max,
+            is_integer,
+        });
     }
     HighsProblem {
         sense,
         highs_problem,
         columns,
         n_constraints: 0,
+        options: Vec::new(),
+        column_defs,
+        rows: Vec::new(),
     }
 }
 
+/// A single row of the problem, recorded separately from the HiGHS model itself so that
+/// [`HighsProblem::compute_iis`] can rebuild reduced problems that include both plain
+/// constraints added through [`SolverModel::add_constraint`] and ranged constraints added
+/// through [`HighsProblem::add_range_constraint`].
+#[derive(Debug, Clone)]
+enum Row {
+    Plain(Constraint),
+    Range {
+        lower: f64,
+        expression: Expression,
+        upper: f64,
+    },
+}
+
+/// The variable bounds and objective coefficient needed to rebuild a column from scratch,
+/// used by [`HighsProblem::compute_iis`] to re-solve reduced problems.
+#[derive(Debug, Clone, Copy)]
+struct ColumnDef {
+    cost: f64,
+    min: f64,
+    max: f64,
+    is_integer: bool,
+}
+
 /// A HiGHS model
 #[derive(Debug)]
 pub struct HighsProblem {
@@ -46,31 +92,224 @@ pub struct HighsProblem {
     highs_problem: highs::RowProblem,
     columns: Vec<highs::Col>,
     n_constraints: usize,
+    options: Vec<(String, HighsOptionValue)>,
+    column_defs: Vec<ColumnDef>,
+    rows: Vec<(usize, Row)>,
+}
+
+/// A value that can be passed to [`HighsProblem::set_option`], mirroring the option types
+/// accepted by HiGHS's `setHighsOptionValue`.
+#[derive(Debug, Clone)]
+pub enum HighsOptionValue {
+    /// A boolean option, such as `output_flag`.
+    Bool(bool),
+    /// An integer option, such as `threads`.
+    Int(i32),
+    /// A floating-point option, such as `time_limit` or `mip_rel_gap`.
+    Float(f64),
+    /// A string option, such as `presolve`.
+    Str(String),
 }
 
 impl HighsProblem {
     /// Get a highs model for this problem
     pub fn into_inner(self) -> highs::Model {
-        self.highs_problem.optimise(self.sense)
+        let mut model = self.highs_problem.optimise(self.sense);
+        for (option, value) in self.options {
+            match value {
+                HighsOptionValue::Bool(v) => model.set_option(option, v),
+                HighsOptionValue::Int(v) => model.set_option(option, v),
+                HighsOptionValue::Float(v) => model.set_option(option, v),
+                HighsOptionValue::Str(v) => model.set_option(option, v.as_str()),
+            }
+        }
+        model
+    }
+
+    /// Set a raw HiGHS option by name, such as `"time_limit"` or `"mip_rel_gap"`.
+    /// See the [HiGHS documentation](https://ergo-code.github.io/HiGHS/dev/options/definitions/)
+    /// for the full list of available options.
+    pub fn set_option(
+        mut self,
+        option: impl Into<String>,
+        value: impl Into<HighsOptionValue>,
+    ) -> Self {
+        self.options.push((option.into(), value.into()));
+        self
+    }
+
+    /// Stop the solve after the given number of seconds, returning the best solution found so far.
+    pub fn with_time_limit(self, seconds: f64) -> Self {
+        self.set_option("time_limit", seconds)
+    }
+
+    /// Set the number of threads HiGHS is allowed to use.
+    pub fn with_threads(self, threads: u32) -> Self {
+        self.set_option("threads", threads as i32)
+    }
+
+    /// Set the relative MIP gap at which the branch-and-bound search may stop.
+    pub fn with_mip_gap(self, mip_gap: f64) -> Self {
+        self.set_option("mip_rel_gap", mip_gap)
+    }
+
+    /// Enable or disable presolve.
+    pub fn with_presolve(self, enabled: bool) -> Self {
+        self.set_option("presolve", if enabled { "on" } else { "off" })
+    }
+
+    /// Enable or disable HiGHS's own solver output.
+    pub fn set_verbose(self, verbose: bool) -> Self {
+        self.set_option("output_flag", verbose)
     }
 
     /// Default implementation for adding a constraint to the Problem
     fn put_constraint(&mut self, constraint: Constraint) {
-        let upper_bound = -constraint.expression.constant();
-        let columns = &self.columns;
-        let factors = constraint
-            .expression
-            .linear_coefficients()
-            .into_iter()
-            .map(|(variable, factor)| (columns[variable.index()], factor));
-        if constraint.is_equality {
-            self.highs_problem
-                .add_row(upper_bound..=upper_bound, factors);
-        } else {
-            self.highs_problem.add_row(..=upper_bound, factors);
-        }
+        let row = Row::Plain(constraint);
+        add_row(&mut self.highs_problem, &self.columns, &row);
+        self.rows.push((self.n_constraints, row));
         self.n_constraints += 1;
     }
+
+    /// Add a two-sided (ranged) row constraint `lower <= expression <= upper` directly,
+    /// instead of encoding the band as two separate `<=` rows.
+    pub fn add_range_constraint(
+        &mut self,
+        lower: f64,
+        expression: impl IntoAffineExpression,
+        upper: f64,
+    ) -> ConstraintReference {
+        let row = Row::Range {
+            lower,
+            expression: expression.into_expression(),
+            upper,
+        };
+        add_row(&mut self.highs_problem, &self.columns, &row);
+        self.rows.push((self.n_constraints, row));
+        self.n_constraints += 1;
+        ConstraintReference {
+            index: self.n_constraints - 1,
+        }
+    }
+
+    /// Compute an Irreducible Infeasible Subset (IIS) for this problem, i.e. a minimal set of
+    /// constraints that is infeasible as a whole but becomes feasible if any single member is
+    /// removed.
+    ///
+    /// This is an opt-in diagnostic: it re-solves the problem once per constraint using a
+    /// *deletion filter* (drop a constraint, re-solve; keep the drop only if the reduced
+    /// problem is still infeasible), so it costs roughly as many solves as there are
+    /// constraints. It runs its own feasibility probe internally, so call it directly on the
+    /// model you've built (before handing it to [`SolverModel::solve`], which consumes `self`)
+    /// rather than trying to call it after `solve()` has reported
+    /// [`ResolutionError::Infeasible`] — by then the `HighsProblem` has already been moved.
+    pub fn compute_iis(&self) -> IisResult {
+        let inconsistent_bounds: Vec<usize> = self
+            .column_defs
+            .iter()
+            .enumerate()
+            .filter(|(_, def)| def.min > def.max)
+            .map(|(index, _)| index)
+            .collect();
+        if !inconsistent_bounds.is_empty() {
+            return IisResult::InconsistentBounds(inconsistent_bounds);
+        }
+
+        let rows: Vec<Row> = self.rows.iter().map(|(_, r)| r.clone()).collect();
+        if rows.is_empty() || !Self::solves_infeasible(&self.column_defs, self.sense, &rows) {
+            return IisResult::Feasible;
+        }
+
+        let mut essential = self.rows.clone();
+        let mut i = 0;
+        while i < essential.len() {
+            let without_i: Vec<Row> = essential
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, (_, r))| r.clone())
+                .collect();
+            if Self::solves_infeasible(&self.column_defs, self.sense, &without_i) {
+                essential.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+        IisResult::Rows(
+            essential
+                .into_iter()
+                .map(|(index, _)| ConstraintReference { index })
+                .collect(),
+        )
+    }
+
+    /// Build a fresh, throwaway HiGHS model from `column_defs` and `rows`, and report whether
+    /// it is primal infeasible. Used by [`Self::compute_iis`] to probe reduced problems.
+    fn solves_infeasible(column_defs: &[ColumnDef], sense: highs::Sense, rows: &[Row]) -> bool {
+        let mut problem = highs::RowProblem::default();
+        let columns: Vec<highs::Col> = column_defs
+            .iter()
+            .map(|def| {
+                if def.is_integer {
+                    problem.add_integer_column(def.cost, def.min..def.max)
+                } else {
+                    problem.add_column(def.cost, def.min..def.max)
+                }
+            })
+            .collect();
+        for row in rows {
+            add_row(&mut problem, &columns, row);
+        }
+        let solved = problem.optimise(sense).solve();
+        matches!(solved.status(), HighsModelStatus::PrimalInfeasible)
+    }
+}
+
+/// Add a single [`Row`] to a `highs::RowProblem`, given the `highs::Col` handles already
+/// allocated for its variables. Shared by [`HighsProblem::put_constraint`],
+/// [`HighsProblem::add_range_constraint`] and [`HighsProblem::solves_infeasible`] so the
+/// constant-folding and equality-vs-inequality logic can't silently diverge between them.
+fn add_row(problem: &mut highs::RowProblem, columns: &[highs::Col], row: &Row) {
+    match row {
+        Row::Plain(constraint) => {
+            let upper_bound = -constraint.expression.constant();
+            let factors = constraint
+                .expression
+                .linear_coefficients()
+                .into_iter()
+                .map(|(variable, factor)| (columns[variable.index()], factor));
+            if constraint.is_equality {
+                problem.add_row(upper_bound..=upper_bound, factors);
+            } else {
+                problem.add_row(..=upper_bound, factors);
+            }
+        }
+        Row::Range {
+            lower,
+            expression,
+            upper,
+        } => {
+            let constant = expression.constant();
+            let factors = expression
+                .linear_coefficients()
+                .into_iter()
+                .map(|(variable, factor)| (columns[variable.index()], factor));
+            problem.add_row((*lower - constant)..=(*upper - constant), factors);
+        }
+    }
+}
+
+/// The result of [`HighsProblem::compute_iis`].
+#[derive(Debug, Clone)]
+pub enum IisResult {
+    /// The constraint set is feasible: there is no infeasible subset to report.
+    Feasible,
+    /// One or more variables have `min > max`, which is infeasible independently of any row
+    /// constraint. Holds the indices of the offending variables.
+    InconsistentBounds(Vec<usize>),
+    /// A minimal set of constraints that is infeasible as a whole but becomes feasible if any
+    /// one of them is removed.
+    Rows(Vec<ConstraintReference>),
 }
 
 impl SolverModel for HighsProblem {
@@ -90,10 +329,26 @@ impl SolverModel for HighsProblem {
             HighsModelStatus::ModelEmpty => Err(ResolutionError::Other("ModelEmpty")),
             HighsModelStatus::PrimalInfeasible => Err(ResolutionError::Infeasible),
             HighsModelStatus::PrimalUnbounded => Err(ResolutionError::Unbounded),
-            _ok_status => Ok(HighsSolution {
+            // Only this status means HiGHS actually proved optimality. Everything else that
+            // isn't one of the error/infeasible/unbounded cases above (a time/iteration/solution/
+            // memory limit, an interrupt, a dual objective bound, or any status HiGHS might add
+            // in the future) is treated as "stopped early" rather than assumed optimal, so new
+            // limit statuses fail safe instead of silently becoming `Optimal`.
+            HighsModelStatus::Optimal => Ok(HighsSolution {
+                solution: solved.get_solution(),
+                dual_values: vec![],
+                acquired: false,
+                column_dual_values: vec![],
+                column_duals_acquired: false,
+                status: HighsSolutionStatus::Optimal,
+            }),
+            _reached_limit => Ok(HighsSolution {
                 solution: solved.get_solution(),
                 dual_values: vec![],
                 acquired: false,
+                column_dual_values: vec![],
+                column_duals_acquired: false,
+                status: HighsSolutionStatus::ReachedLimit,
             }),
         }
     }
@@ -107,12 +362,28 @@ impl SolverModel for HighsProblem {
     }
 }
 
+/// Whether a [HighsSolution] is a proven optimum, or an incumbent the solver returned early
+/// because one of its stopping limits was reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighsSolutionStatus {
+    /// The solution is a proven optimum.
+    Optimal,
+    /// The solver stopped before proving optimality — for example because
+    /// [`HighsProblem::with_time_limit`] was reached, an iteration/solution/memory limit was
+    /// hit, or the search was interrupted. This is the best incumbent HiGHS found so far, not a
+    /// proven optimum.
+    ReachedLimit,
+}
+
 /// The solution to a highs problem
 #[derive(Debug)]
 pub struct HighsSolution {
     solution: highs::Solution,
     dual_values: Vec<f64>,
     acquired: bool,
+    column_dual_values: Vec<f64>,
+    column_duals_acquired: bool,
+    status: HighsSolutionStatus,
 }
 
 impl HighsSolution {
@@ -120,6 +391,27 @@ impl HighsSolution {
     pub fn into_inner(self) -> highs::Solution {
         self.solution
     }
+
+    /// Whether this solution is a proven optimum, or an incumbent returned early because the
+    /// solver reached one of its configured limits. Check this when using
+    /// [`HighsProblem::with_time_limit`] or a similar limit, since the returned point may then
+    /// be feasible but not optimal.
+    pub fn status(&self) -> HighsSolutionStatus {
+        self.status
+    }
+
+    /// The reduced cost of `variable`: the dual value associated with its bounds, as opposed to
+    /// [`SolutionWithDual::dual`] which gives the dual value of a row constraint.
+    ///
+    /// Lazily populated from the underlying HiGHS solution the first time it's called, the same
+    /// way [`Dual::get_dual`] lazily populates row duals.
+    pub fn reduced_cost(&mut self, variable: Variable) -> f64 {
+        if !self.column_duals_acquired {
+            self.column_dual_values = self.solution.dual_columns().to_vec();
+            self.column_duals_acquired = true;
+        }
+        self.column_dual_values[variable.index()]
+    }
 }
 
 impl Solution for HighsSolution {
@@ -144,3 +436,27 @@ impl<'a> Dual<'_> for HighsSolution {
         self
     }
 }
+
+impl From<bool> for HighsOptionValue {
+    fn from(value: bool) -> Self {
+        HighsOptionValue::Bool(value)
+    }
+}
+
+impl From<i32> for HighsOptionValue {
+    fn from(value: i32) -> Self {
+        HighsOptionValue::Int(value)
+    }
+}
+
+impl From<f64> for HighsOptionValue {
+    fn from(value: f64) -> Self {
+        HighsOptionValue::Float(value)
+    }
+}
+
+impl From<&str> for HighsOptionValue {
+    fn from(value: &str) -> Self {
+        HighsOptionValue::Str(value.to_owned())
+    }
+}
@@ -0,0 +1,55 @@
+//! Regression tests for opt-in IIS (Irreducible Infeasible Subset) computation on the HiGHS
+//! backend, including the edge cases called out when the feature was added: an already-feasible
+//! problem must report an empty set, and bound-induced infeasibilities must be reported
+//! separately from row conflicts.
+#![cfg(feature = "highs")]
+
+use good_lp::solvers::highs::{highs, IisResult};
+use good_lp::{constraint, variable, ProblemVariables, SolverModel};
+
+#[test]
+fn compute_iis_on_a_feasible_problem_is_empty() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().min(0).max(10));
+    let model = vars.minimise(x).using(highs).with(constraint!(x >= 1));
+    assert!(matches!(model.compute_iis(), IisResult::Feasible));
+}
+
+#[test]
+fn compute_iis_finds_the_conflicting_row_constraints() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().min(0).max(10));
+    let model = vars
+        .minimise(x)
+        .using(highs)
+        .with(constraint!(x >= 8))
+        .with(constraint!(x <= 2));
+    match model.compute_iis() {
+        IisResult::Rows(rows) => assert_eq!(rows.len(), 2),
+        other => panic!("expected a conflicting row set, got {other:?}"),
+    }
+}
+
+#[test]
+fn compute_iis_finds_a_conflict_involving_a_ranged_constraint() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().min(0).max(10));
+    let mut model = vars.minimise(x).using(highs);
+    model.add_range_constraint(4., x, 6.);
+    model.add_constraint(constraint!(x <= 2));
+    match model.compute_iis() {
+        IisResult::Rows(rows) => assert_eq!(rows.len(), 2),
+        other => panic!("expected the ranged row to be part of the conflict, got {other:?}"),
+    }
+}
+
+#[test]
+fn compute_iis_reports_inconsistent_bounds_separately_from_row_conflicts() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().min(5.).max(1.));
+    let model = vars.minimise(x).using(highs);
+    assert!(matches!(
+        model.compute_iis(),
+        IisResult::InconsistentBounds(_)
+    ));
+}
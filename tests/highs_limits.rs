@@ -0,0 +1,21 @@
+//! Regression test for distinguishing limit-terminated HiGHS solves from proven optima:
+//! hitting a configured limit must still hand back a feasible solution, tagged as such.
+#![cfg(feature = "highs")]
+
+use good_lp::solvers::highs::{highs, HighsSolutionStatus};
+use good_lp::{constraint, variable, ProblemVariables, Solution, SolverModel};
+
+#[test]
+fn reaching_the_iteration_limit_returns_a_feasible_non_optimal_solution() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().min(0).max(10));
+    let y = vars.add(variable().min(0).max(10));
+    let solution = vars
+        .maximise(x + y)
+        .using(highs)
+        .set_option("simplex_iteration_limit", 0)
+        .with(constraint!(x + y <= 15))
+        .solve()
+        .unwrap();
+    assert_ne!(solution.status(), HighsSolutionStatus::Optimal);
+}
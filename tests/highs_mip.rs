@@ -0,0 +1,33 @@
+//! Regression test for mixed-integer support in the HiGHS backend: marking a variable
+//! integer must actually change the returned solution, not just get ignored.
+#![cfg(feature = "highs")]
+
+use good_lp::solvers::highs::highs;
+use good_lp::{constraint, variable, ProblemVariables, ResolutionError, Solution, SolverModel};
+
+#[test]
+fn integer_variable_is_rounded_to_an_integral_solution() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().integer().min(0).max(10));
+    let solution = vars
+        .maximise(x)
+        .using(highs)
+        .with(constraint!(2 * x <= 7))
+        .solve()
+        .unwrap();
+    // The continuous optimum is 3.5; with x declared integer, HiGHS must branch down to 3.
+    assert_eq!(solution.value(x), 3.);
+}
+
+#[test]
+fn integer_infeasible_problem_is_reported_as_infeasible_not_optimal() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().integer().min(0).max(10));
+    // The LP relaxation of `2*x == 1` is feasible at x = 0.5, but no integer x satisfies it.
+    let result = vars
+        .maximise(x)
+        .using(highs)
+        .with(constraint!(2 * x == 1))
+        .solve();
+    assert!(matches!(result, Err(ResolutionError::Infeasible)));
+}
@@ -0,0 +1,36 @@
+//! Regression tests for the HiGHS solver option builder methods.
+#![cfg(feature = "highs")]
+
+use good_lp::solvers::highs::{highs, HighsSolutionStatus};
+use good_lp::{constraint, variable, ProblemVariables, Solution, SolverModel};
+
+#[test]
+fn with_time_limit_stops_the_solve_before_proving_optimality() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().integer().min(0).max(1_000_000));
+    let y = vars.add(variable().integer().min(0).max(1_000_000));
+    let solution = vars
+        .maximise(x + y)
+        .using(highs)
+        .with_time_limit(0.0)
+        .with(constraint!(x + y <= 1_000_000))
+        .solve()
+        .unwrap();
+    assert_ne!(solution.status(), HighsSolutionStatus::Optimal);
+}
+
+#[test]
+fn with_mip_gap_is_forwarded_to_highs_without_panicking() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().integer().min(0).max(10));
+    // If `with_mip_gap` forwarded a mistyped option name, HiGHS would reject it and this call
+    // would panic rather than silently doing nothing.
+    let solution = vars
+        .maximise(x)
+        .using(highs)
+        .with_mip_gap(0.1)
+        .with(constraint!(x <= 7))
+        .solve()
+        .unwrap();
+    assert_eq!(solution.value(x), 7.);
+}
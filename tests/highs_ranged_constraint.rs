@@ -0,0 +1,16 @@
+//! Regression test for two-sided (ranged) row constraints in the HiGHS backend.
+#![cfg(feature = "highs")]
+
+use good_lp::solvers::highs::highs;
+use good_lp::{variable, ProblemVariables, Solution, SolverModel};
+
+#[test]
+fn ranged_constraint_bounds_the_expression_on_both_sides() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().min(0).max(10));
+    let mut model = vars.minimise(x).using(highs);
+    model.add_range_constraint(3., x, 8.);
+    let solution = model.solve().unwrap();
+    // Minimising x subject to 3 <= x <= 8 must hit the range's lower bound, not x's own min of 0.
+    assert_eq!(solution.value(x), 3.);
+}
@@ -0,0 +1,19 @@
+//! Regression test for exposing column duals (reduced costs) on HighsSolution.
+#![cfg(feature = "highs")]
+
+use good_lp::solvers::highs::highs;
+use good_lp::{constraint, variable, ProblemVariables, Solution, SolverModel};
+
+#[test]
+fn reduced_cost_is_nonzero_for_a_variable_pinned_at_its_bound() {
+    let mut vars = ProblemVariables::new();
+    let x = vars.add(variable().min(0).max(10));
+    let mut solution = vars
+        .maximise(x)
+        .using(highs)
+        .with(constraint!(x <= 10))
+        .solve()
+        .unwrap();
+    assert_eq!(solution.value(x), 10.);
+    assert_ne!(solution.reduced_cost(x), 0.);
+}